@@ -0,0 +1,262 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::chat_completion::{
+    errors::ChatCompletionError, ChatCompletionRequest, ChatMessage, ToolCall, ToolOutput,
+    ToolSpec,
+};
+use crate::{AgentContext, ChatCompletion, Tool, ToolKind};
+
+/// Drives a conversation with a [`ChatCompletion`] backend, automatically invoking any
+/// [`Tool`]s the model calls and feeding their output back in, until the model answers without
+/// requesting more tools or `max_iterations` is reached.
+///
+/// Tool results are cached per `(name, args)` pair for the lifetime of a single [`Self::run`]
+/// call, so a model that calls the same tool with the same arguments twice (e.g. retrying
+/// after an unrelated tool call) reuses the first result instead of invoking it again. This is
+/// keyed on `(name, args)` rather than `ToolCall::id` because the id is provider-assigned and
+/// unique per call, even when the name and arguments are identical.
+pub struct ToolCallLoop {
+    model: Arc<dyn ChatCompletion>,
+    tools: Vec<Arc<dyn Tool>>,
+    max_iterations: usize,
+    confirm_execute: Option<Box<dyn Fn(&ToolCall) -> bool + Send + Sync>>,
+}
+
+impl ToolCallLoop {
+    pub fn new(model: impl ChatCompletion + 'static, tools: Vec<Arc<dyn Tool>>) -> Self {
+        Self {
+            model: Arc::new(model),
+            tools,
+            max_iterations: 10,
+            confirm_execute: None,
+        }
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Called before invoking any [`ToolKind::Execute`] tool; the tool only runs if this
+    /// returns `true`. Left unset, `Execute` tools run without confirmation.
+    pub fn with_confirm_execute(
+        mut self,
+        confirm: impl Fn(&ToolCall) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.confirm_execute = Some(Box::new(confirm));
+        self
+    }
+
+    /// Runs `messages` through the model until it returns a final answer with no tool calls.
+    /// Returns the full message history, including the assistant's tool calls and their
+    /// outputs, so callers can persist or continue the conversation.
+    pub async fn run(
+        &self,
+        agent_context: &dyn AgentContext,
+        mut messages: Vec<ChatMessage>,
+        tools_spec: HashSet<ToolSpec>,
+    ) -> Result<Vec<ChatMessage>, ChatCompletionError> {
+        let mut tool_results: HashMap<(String, Option<String>), ToolOutput> = HashMap::new();
+
+        for _ in 0..self.max_iterations {
+            let request = ChatCompletionRequest::builder()
+                .messages(messages.clone())
+                .tools_spec(tools_spec.clone())
+                .build()?;
+
+            let response = self.model.complete(&request).await?;
+
+            let Some(tool_calls) = response.tool_calls.clone() else {
+                messages.push(ChatMessage::Assistant(response.message, None));
+                return Ok(messages);
+            };
+
+            messages.push(ChatMessage::Assistant(
+                response.message,
+                Some(tool_calls.clone()),
+            ));
+
+            for tool_call in tool_calls {
+                let output = self.resolve(agent_context, &tool_call, &mut tool_results).await?;
+                messages.push(ChatMessage::ToolOutput(tool_call, output));
+            }
+        }
+
+        Err(ChatCompletionError::Other(anyhow::anyhow!(
+            "tool-calling loop exceeded max_iterations ({}) without a final answer",
+            self.max_iterations
+        )))
+    }
+
+    async fn resolve(
+        &self,
+        agent_context: &dyn AgentContext,
+        tool_call: &ToolCall,
+        tool_results: &mut HashMap<(String, Option<String>), ToolOutput>,
+    ) -> Result<ToolOutput, ChatCompletionError> {
+        let cache_key = (tool_call.name().to_string(), tool_call.args().map(str::to_string));
+
+        if let Some(cached) = tool_results.get(&cache_key) {
+            return Ok(cached.clone());
+        }
+
+        let tool = self
+            .tools
+            .iter()
+            .find(|tool| tool.name() == tool_call.name())
+            .ok_or_else(|| ChatCompletionError::InvalidToolCall {
+                name: tool_call.name().to_string(),
+                reason: "no tool registered with this name".into(),
+            })?;
+
+        if tool.kind() == ToolKind::Execute {
+            let confirmed = self
+                .confirm_execute
+                .as_ref()
+                .map_or(true, |confirm| confirm(tool_call));
+
+            if !confirmed {
+                return Err(ChatCompletionError::InvalidToolCall {
+                    name: tool_call.name().to_string(),
+                    reason: "execution was not confirmed".into(),
+                });
+            }
+        }
+
+        let output = tool
+            .invoke(agent_context, tool_call.args())
+            .await
+            .map_err(|error| ChatCompletionError::Other(error.into()))?;
+
+        tool_results.insert(cache_key, output.clone());
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::chat_completion::errors::ToolError;
+    use crate::chat_completion::ChatCompletionResponse;
+
+    struct ScriptedModel {
+        responses: Mutex<Vec<ChatCompletionResponse>>,
+    }
+
+    #[async_trait]
+    impl ChatCompletion for ScriptedModel {
+        async fn complete(
+            &self,
+            _request: &ChatCompletionRequest,
+        ) -> Result<ChatCompletionResponse, ChatCompletionError> {
+            Ok(self.responses.lock().unwrap().remove(0))
+        }
+    }
+
+    struct NoopContext;
+
+    impl AgentContext for NoopContext {}
+
+    struct CountingTool {
+        invocations: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Tool for CountingTool {
+        async fn invoke(
+            &self,
+            _agent_context: &dyn AgentContext,
+            _raw_args: Option<&str>,
+        ) -> Result<ToolOutput, ToolError> {
+            self.invocations.fetch_add(1, Ordering::SeqCst);
+            Ok(ToolOutput::new("42"))
+        }
+
+        fn name(&self) -> &'static str {
+            "get_answer"
+        }
+
+        fn tool_spec(&self) -> ToolSpec {
+            ToolSpec::builder()
+                .name("get_answer")
+                .description("Gets the answer")
+                .build()
+                .unwrap()
+        }
+    }
+
+    fn tool_call() -> ToolCall {
+        ToolCall::builder()
+            .id("call_1")
+            .name("get_answer")
+            .args("{}")
+            .build()
+            .unwrap()
+    }
+
+    fn tool_call_response() -> ChatCompletionResponse {
+        ChatCompletionResponse::builder()
+            .maybe_message(None)
+            .maybe_tool_calls(Some(vec![tool_call()]))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_repeated_tool_call_is_resolved_from_cache() {
+        // A second, distinct `ToolCall` (fresh provider-assigned id) but with the same
+        // name+args as the first — this must hit the cache rather than invoke the tool again.
+        let repeated_tool_call = ToolCall::builder()
+            .id("call_2")
+            .name("get_answer")
+            .args("{}")
+            .build()
+            .unwrap();
+
+        let model = ScriptedModel {
+            responses: Mutex::new(vec![
+                tool_call_response(),
+                ChatCompletionResponse::builder()
+                    .maybe_message(None)
+                    .maybe_tool_calls(Some(vec![repeated_tool_call]))
+                    .build()
+                    .unwrap(),
+                ChatCompletionResponse::builder()
+                    .maybe_message(Some("done".into()))
+                    .maybe_tool_calls(None)
+                    .build()
+                    .unwrap(),
+            ]),
+        };
+
+        let tool = Arc::new(CountingTool {
+            invocations: AtomicUsize::new(0),
+        });
+
+        let tool_loop = ToolCallLoop::new(model, vec![tool.clone()]);
+
+        let messages = tool_loop
+            .run(
+                &NoopContext,
+                vec![ChatMessage::User("what's the answer?".into())],
+                HashSet::from([tool.tool_spec()]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(tool.invocations.load(Ordering::SeqCst), 1);
+
+        let tool_outputs = messages
+            .iter()
+            .filter(|message| matches!(message, ChatMessage::ToolOutput(_, _)))
+            .count();
+        assert_eq!(tool_outputs, 2);
+    }
+}
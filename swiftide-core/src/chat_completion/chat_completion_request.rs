@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use derive_builder::Builder;
+
+use super::{ChatMessage, ToolSpec};
+
+#[derive(Clone, Debug, Builder)]
+#[builder(setter(into))]
+pub struct ChatCompletionRequest {
+    messages: Vec<ChatMessage>,
+
+    #[builder(default)]
+    pub tools_spec: HashSet<ToolSpec>,
+
+    #[builder(default)]
+    pub options: ChatCompletionRequestOptions,
+
+    #[builder(default)]
+    pub tool_choice: ToolChoice,
+}
+
+impl ChatCompletionRequest {
+    pub fn builder() -> ChatCompletionRequestBuilder {
+        ChatCompletionRequestBuilder::default()
+    }
+
+    pub fn messages(&self) -> &[ChatMessage] {
+        &self.messages
+    }
+
+    pub fn tools_spec(&self) -> &HashSet<ToolSpec> {
+        &self.tools_spec
+    }
+
+    pub fn options(&self) -> &ChatCompletionRequestOptions {
+        &self.options
+    }
+
+    pub fn tool_choice(&self) -> &ToolChoice {
+        &self.tool_choice
+    }
+}
+
+/// Whether (and how) a model should be made to use a tool, independent of which tools are
+/// available: let the model decide, force it to call some tool, force a specific one, or
+/// forbid tool use entirely. Not every backend honours every choice; one that cannot express
+/// `Required` or `Specific` leaves the choice to the provider's default.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ToolChoice {
+    #[default]
+    Auto,
+    Required,
+    Specific(String),
+    None,
+}
+
+/// Sampling and output-length controls for a [`ChatCompletionRequest`]. Every field is
+/// optional. When `max_tokens` is unset, a backend whose provider mandates a token cap sends
+/// one fixed default, the same for every model; the other fields, when unset, are left out of
+/// the request so the provider's own defaults apply.
+#[derive(Clone, Debug, Default, Builder)]
+#[builder(setter(into, strip_option), default)]
+pub struct ChatCompletionRequestOptions {
+    pub max_tokens: Option<u32>,
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+    pub stop_sequences: Option<Vec<String>>,
+}
+
+impl ChatCompletionRequestOptions {
+    pub fn builder() -> ChatCompletionRequestOptionsBuilder {
+        ChatCompletionRequestOptionsBuilder::default()
+    }
+}
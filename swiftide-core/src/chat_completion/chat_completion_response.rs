@@ -0,0 +1,31 @@
+use derive_builder::Builder;
+
+use super::ToolCall;
+
+#[derive(Clone, Debug, Default, Builder)]
+#[builder(default)]
+pub struct ChatCompletionResponse {
+    #[builder(setter(custom))]
+    pub message: Option<String>,
+
+    #[builder(setter(custom))]
+    pub tool_calls: Option<Vec<ToolCall>>,
+}
+
+impl ChatCompletionResponse {
+    pub fn builder() -> ChatCompletionResponseBuilder {
+        ChatCompletionResponseBuilder::default()
+    }
+}
+
+impl ChatCompletionResponseBuilder {
+    pub fn maybe_message(&mut self, message: Option<String>) -> &mut Self {
+        self.message = Some(message);
+        self
+    }
+
+    pub fn maybe_tool_calls(&mut self, tool_calls: Option<Vec<ToolCall>>) -> &mut Self {
+        self.tool_calls = Some(tool_calls);
+        self
+    }
+}
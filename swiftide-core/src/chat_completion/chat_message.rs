@@ -0,0 +1,11 @@
+use super::{ToolCall, ToolOutput};
+
+/// A single turn in a conversation handed to a [`crate::ChatCompletion`] backend.
+#[derive(Clone, Debug)]
+pub enum ChatMessage {
+    System(String),
+    User(String),
+    Summary(String),
+    Assistant(Option<String>, Option<Vec<ToolCall>>),
+    ToolOutput(ToolCall, ToolOutput),
+}
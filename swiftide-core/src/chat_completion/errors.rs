@@ -0,0 +1,48 @@
+use thiserror::Error;
+
+use super::{
+    chat_completion_request::ChatCompletionRequestBuilderError,
+    chat_completion_response::ChatCompletionResponseBuilderError, tool_call::ToolCallBuilderError,
+};
+
+#[derive(Debug, Error)]
+pub enum ChatCompletionError {
+    #[error("malformed response from model: {0}")]
+    MalformedResponse(String),
+
+    #[error("tool call '{name}' is invalid: {reason}")]
+    InvalidToolCall { name: String, reason: String },
+
+    #[error("error calling LLM: {0}")]
+    LLM(#[from] Box<dyn std::error::Error + Send + Sync>),
+
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+impl From<ChatCompletionResponseBuilderError> for ChatCompletionError {
+    fn from(value: ChatCompletionResponseBuilderError) -> Self {
+        ChatCompletionError::MalformedResponse(value.to_string())
+    }
+}
+
+impl From<ToolCallBuilderError> for ChatCompletionError {
+    fn from(value: ToolCallBuilderError) -> Self {
+        ChatCompletionError::MalformedResponse(value.to_string())
+    }
+}
+
+impl From<ChatCompletionRequestBuilderError> for ChatCompletionError {
+    fn from(value: ChatCompletionRequestBuilderError) -> Self {
+        ChatCompletionError::MalformedResponse(value.to_string())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ToolError {
+    #[error("missing required argument: {0}")]
+    MissingArgument(String),
+
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
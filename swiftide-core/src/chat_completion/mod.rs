@@ -0,0 +1,15 @@
+pub mod errors;
+
+mod chat_completion_request;
+mod chat_completion_response;
+mod chat_message;
+mod tool_call;
+mod tool_output;
+mod tool_spec;
+
+pub use chat_completion_request::{ChatCompletionRequest, ChatCompletionRequestOptions, ToolChoice};
+pub use chat_completion_response::ChatCompletionResponse;
+pub use chat_message::ChatMessage;
+pub use tool_call::ToolCall;
+pub use tool_output::ToolOutput;
+pub use tool_spec::{ParamSpec, ParamType, ToolSpec};
@@ -0,0 +1,35 @@
+use derive_builder::Builder;
+
+#[derive(Clone, Debug, Builder)]
+#[builder(setter(into))]
+pub struct ToolCall {
+    id: String,
+    name: String,
+    args: Option<String>,
+}
+
+impl ToolCall {
+    pub fn builder() -> ToolCallBuilder {
+        ToolCallBuilder::default()
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn args(&self) -> Option<&str> {
+        self.args.as_deref()
+    }
+
+    /// Parses `args` as JSON, returning an empty object when no arguments were provided.
+    pub fn parsed_args(&self) -> Result<serde_json::Value, serde_json::Error> {
+        match &self.args {
+            Some(args) => serde_json::from_str(args),
+            None => Ok(serde_json::json!({})),
+        }
+    }
+}
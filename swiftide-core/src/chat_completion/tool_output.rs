@@ -0,0 +1,17 @@
+/// The result of invoking a [`crate::Tool`].
+#[derive(Clone, Debug, Default)]
+pub struct ToolOutput {
+    content: Option<String>,
+}
+
+impl ToolOutput {
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: Some(content.into()),
+        }
+    }
+
+    pub fn content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+}
@@ -0,0 +1,199 @@
+use std::hash::{Hash, Hasher};
+
+use derive_builder::Builder;
+use serde_json::json;
+
+#[derive(Clone, Debug, Builder)]
+#[builder(setter(into))]
+pub struct ToolSpec {
+    pub name: String,
+    pub description: String,
+    #[builder(default)]
+    pub parameters: Vec<ParamSpec>,
+}
+
+impl ToolSpec {
+    pub fn builder() -> ToolSpecBuilder {
+        ToolSpecBuilder::default()
+    }
+
+    /// Renders `parameters` as a JSON-schema object (`{"type": "object", "properties": ...,
+    /// "required": [...]}`), shared by every backend that needs to describe this tool to a
+    /// model (Anthropic's `input_schema`, Bedrock's `toolConfig`, etc).
+    pub fn parameters_schema(&self) -> serde_json::Value {
+        let mut properties = serde_json::Map::new();
+        for param in &self.parameters {
+            properties.insert(param.name.clone(), param.to_schema());
+        }
+
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": self.parameters.iter().filter(|param| param.required).map(|param| &param.name).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl PartialEq for ToolSpec {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for ToolSpec {}
+
+impl Hash for ToolSpec {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+/// The JSON-schema type of a tool parameter. Mirrors (a useful subset of) the JSON-schema
+/// `type` keyword so `ParamSpec` can describe anything from a plain string to a nested object.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum ParamType {
+    #[default]
+    String,
+    Number,
+    Integer,
+    Boolean,
+    Array {
+        items: Box<ParamType>,
+    },
+    Object {
+        properties: Vec<ParamSpec>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Builder)]
+#[builder(setter(into))]
+pub struct ParamSpec {
+    pub name: String,
+    pub description: String,
+    #[builder(default)]
+    pub required: bool,
+    #[builder(default)]
+    pub ty: ParamType,
+    #[builder(default, setter(strip_option))]
+    pub enum_values: Option<Vec<String>>,
+}
+
+impl ParamSpec {
+    pub fn builder() -> ParamSpecBuilder {
+        ParamSpecBuilder::default()
+    }
+
+    /// Renders this parameter as a JSON-schema fragment, recursing into array items and
+    /// object properties.
+    pub fn to_schema(&self) -> serde_json::Value {
+        let mut schema = self.ty.to_schema();
+
+        let object = schema.as_object_mut().expect("schema is always an object");
+        object.insert("description".into(), json!(self.description));
+
+        if let Some(enum_values) = &self.enum_values {
+            object.insert("enum".into(), json!(enum_values));
+        }
+
+        schema
+    }
+}
+
+impl ParamType {
+    pub fn to_schema(&self) -> serde_json::Value {
+        match self {
+            ParamType::String => json!({"type": "string"}),
+            ParamType::Number => json!({"type": "number"}),
+            ParamType::Integer => json!({"type": "integer"}),
+            ParamType::Boolean => json!({"type": "boolean"}),
+            ParamType::Array { items } => json!({
+                "type": "array",
+                "items": items.to_schema(),
+            }),
+            ParamType::Object { properties } => {
+                let mut object_properties = serde_json::Map::new();
+                for param in properties {
+                    object_properties.insert(param.name.clone(), param.to_schema());
+                }
+
+                json!({
+                    "type": "object",
+                    "properties": object_properties,
+                    "required": properties.iter().filter(|param| param.required).map(|param| &param.name).collect::<Vec<_>>(),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parameters_schema_nests_required_inside_schema_object() {
+        // Regression coverage for a shape bug that shipped silently: `required` rendered as a
+        // sibling of `input_schema` instead of nested inside it, which drops all `required`
+        // enforcement on the Anthropic/Bedrock side since neither looks outside the schema
+        // object for it.
+        let spec = ToolSpec::builder()
+            .name("get_weather")
+            .description("Gets the weather")
+            .parameters(vec![
+                ParamSpec::builder()
+                    .name("location")
+                    .description("City name")
+                    .required(true)
+                    .build()
+                    .unwrap(),
+                ParamSpec::builder()
+                    .name("unit")
+                    .description("Temperature unit")
+                    .ty(ParamType::String)
+                    .enum_values(vec!["celsius".to_string(), "fahrenheit".to_string()])
+                    .build()
+                    .unwrap(),
+            ])
+            .build()
+            .unwrap();
+
+        let schema = spec.parameters_schema();
+
+        assert_eq!(schema["type"], json!("object"));
+        assert_eq!(schema["required"], json!(["location"]));
+        assert_eq!(schema["properties"]["location"]["type"], json!("string"));
+        assert_eq!(
+            schema["properties"]["unit"]["enum"],
+            json!(["celsius", "fahrenheit"])
+        );
+    }
+
+    #[test]
+    fn test_param_type_to_schema_recurses_into_arrays_and_nested_objects() {
+        let param = ParamSpec::builder()
+            .name("stops")
+            .description("Waypoints")
+            .ty(ParamType::Array {
+                items: Box::new(ParamType::Object {
+                    properties: vec![ParamSpec::builder()
+                        .name("city")
+                        .description("City name")
+                        .required(true)
+                        .build()
+                        .unwrap()],
+                }),
+            })
+            .build()
+            .unwrap();
+
+        let schema = param.to_schema();
+
+        assert_eq!(schema["type"], json!("array"));
+        assert_eq!(schema["items"]["type"], json!("object"));
+        assert_eq!(schema["items"]["required"], json!(["city"]));
+        assert_eq!(
+            schema["items"]["properties"]["city"]["type"],
+            json!("string")
+        );
+    }
+}
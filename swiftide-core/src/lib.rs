@@ -0,0 +1,5 @@
+pub mod agent_loop;
+pub mod chat_completion;
+mod traits;
+
+pub use traits::{AgentContext, ChatCompletion, ChatCompletionStream, Tool, ToolKind};
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+
+use crate::chat_completion::{
+    errors::{ChatCompletionError, ToolError},
+    ChatCompletionRequest, ChatCompletionResponse, ToolOutput, ToolSpec,
+};
+
+/// A stream of incremental `ChatCompletionResponse` deltas, as yielded by
+/// [`ChatCompletion::complete_stream`].
+pub type ChatCompletionStream =
+    BoxStream<'static, Result<ChatCompletionResponse, ChatCompletionError>>;
+
+/// Implemented by any backend that can turn a [`ChatCompletionRequest`] into a
+/// [`ChatCompletionResponse`], optionally driven by tools.
+#[async_trait]
+pub trait ChatCompletion: Send + Sync {
+    async fn complete(
+        &self,
+        request: &ChatCompletionRequest,
+    ) -> Result<ChatCompletionResponse, ChatCompletionError>;
+
+    /// Same as [`ChatCompletion::complete`], but streams incremental deltas as the model
+    /// generates them instead of waiting for the full response.
+    ///
+    /// The default implementation falls back to a single, non-incremental item so backends
+    /// that do not support streaming still satisfy the trait.
+    async fn complete_stream(&self, request: &ChatCompletionRequest) -> ChatCompletionStream {
+        let response = self.complete(request).await;
+        Box::pin(futures::stream::once(async move { response }))
+    }
+}
+
+/// Context handed to a [`Tool`] when it is invoked, giving it access to whatever state the
+/// surrounding agent run needs to expose (conversation history, workspace, etc).
+#[async_trait]
+pub trait AgentContext: Send + Sync {}
+
+/// Whether invoking a tool only reads state (`Query`) or performs a side effect (`Execute`).
+/// A driver such as [`crate::agent_loop::ToolCallLoop`] can use this to gate `Execute` tools
+/// behind user confirmation while letting `Query` tools run freely.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToolKind {
+    #[default]
+    Query,
+    Execute,
+}
+
+/// A single callable tool an agent can invoke as part of a [`ChatCompletion`] turn.
+#[async_trait]
+pub trait Tool: Send + Sync {
+    async fn invoke(
+        &self,
+        agent_context: &dyn AgentContext,
+        raw_args: Option<&str>,
+    ) -> Result<ToolOutput, ToolError>;
+
+    fn name(&self) -> &'static str;
+
+    fn tool_spec(&self) -> ToolSpec;
+
+    /// Defaults to [`ToolKind::Query`]; side-effecting tools should override this to
+    /// [`ToolKind::Execute`] so callers can gate them behind confirmation.
+    fn kind(&self) -> ToolKind {
+        ToolKind::Query
+    }
+}
@@ -1,20 +1,28 @@
+use std::collections::HashMap;
+
 use anyhow::{Context as _, Result};
 use async_anthropic::types::{
-    CreateMessagesRequestBuilder, Message, MessageBuilder, MessageContent, MessageContentList,
-    MessageRole, ToolChoice, ToolResultBuilder, ToolUseBuilder,
+    ContentBlock, ContentBlockDelta, CreateMessagesRequestBuilder, Message, MessageBuilder,
+    MessageContent, MessageContentList, MessageRole, MessageStreamEvent,
+    ToolChoice as AnthropicToolChoice, ToolResultBuilder, ToolUseBuilder,
 };
 use async_trait::async_trait;
+use futures::StreamExt;
 use serde_json::json;
 use swiftide_core::{
     chat_completion::{
-        errors::ChatCompletionError, ChatCompletionRequest, ChatCompletionResponse, ChatMessage,
-        ToolCall, ToolSpec,
+        errors::ChatCompletionError, ChatCompletionRequest, ChatCompletionRequestOptions,
+        ChatCompletionResponse, ChatMessage, ToolCall, ToolChoice, ToolSpec,
     },
-    ChatCompletion,
+    ChatCompletion, ChatCompletionStream,
 };
 
 use super::Anthropic;
 
+/// Anthropic's Messages API requires `max_tokens` on every request; fall back to this when
+/// the caller didn't set one via `ChatCompletionRequestOptions`.
+const DEFAULT_MAX_TOKENS: u32 = 4096;
+
 #[async_trait]
 impl ChatCompletion for Anthropic {
     #[tracing::instrument(skip_all, err)]
@@ -27,6 +35,7 @@ impl ChatCompletion for Anthropic {
         let messages = request
             .messages()
             .iter()
+            .filter(|message| !matches!(message, ChatMessage::System(_)))
             .map(message_to_antropic)
             .collect::<Result<Vec<_>>>()?;
 
@@ -35,7 +44,13 @@ impl ChatCompletion for Anthropic {
             .messages(messages)
             .to_owned();
 
-        if !request.tools_spec.is_empty() {
+        if let Some(system) = system_prompt(request.messages()) {
+            anthropic_request.system(system);
+        }
+
+        apply_options(&mut anthropic_request, request.options());
+
+        if !request.tools_spec.is_empty() && *request.tool_choice() != ToolChoice::None {
             anthropic_request
                 .tools(
                     request
@@ -44,7 +59,7 @@ impl ChatCompletion for Anthropic {
                         .map(tools_to_anthropic)
                         .collect::<Result<Vec<_>>>()?,
                 )
-                .tool_choice(ToolChoice::Auto);
+                .tool_choice(anthropic_tool_choice(request.tool_choice()));
         }
 
         let request = anthropic_request
@@ -74,14 +89,23 @@ impl ChatCompletion for Anthropic {
             .iter()
             .flat_map(Message::tool_uses)
             .map(|atool| {
-                ToolCall::builder()
+                let tool_call = ToolCall::builder()
                     .id(atool.id)
                     .name(atool.name)
                     .args(atool.input.to_string())
                     .build()
-                    .expect("infallible")
+                    .expect("infallible");
+
+                tool_call
+                    .parsed_args()
+                    .map_err(|error| ChatCompletionError::InvalidToolCall {
+                        name: tool_call.name().to_string(),
+                        reason: format!("arguments must be valid JSON: {error}"),
+                    })?;
+
+                Ok(tool_call)
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, ChatCompletionError>>()?;
         let maybe_tool_calls = if maybe_tool_calls.is_empty() {
             None
         } else {
@@ -94,6 +118,188 @@ impl ChatCompletion for Anthropic {
             .build()
             .map_err(ChatCompletionError::from)
     }
+
+    #[tracing::instrument(skip_all)]
+    async fn complete_stream(&self, request: &ChatCompletionRequest) -> ChatCompletionStream {
+        let model = self.default_options.prompt_model.clone();
+
+        let messages = match request
+            .messages()
+            .iter()
+            .filter(|message| !matches!(message, ChatMessage::System(_)))
+            .map(message_to_antropic)
+            .collect::<Result<Vec<_>>>()
+        {
+            Ok(messages) => messages,
+            Err(error) => {
+                return Box::pin(futures::stream::once(async move {
+                    Err(ChatCompletionError::from(error))
+                }))
+            }
+        };
+
+        let mut anthropic_request = CreateMessagesRequestBuilder::default()
+            .model(&model)
+            .messages(messages)
+            .stream(true)
+            .to_owned();
+
+        if let Some(system) = system_prompt(request.messages()) {
+            anthropic_request.system(system);
+        }
+
+        apply_options(&mut anthropic_request, request.options());
+
+        if !request.tools_spec.is_empty() && *request.tool_choice() != ToolChoice::None {
+            let tools = match request
+                .tools_spec()
+                .iter()
+                .map(tools_to_anthropic)
+                .collect::<Result<Vec<_>>>()
+            {
+                Ok(tools) => tools,
+                Err(error) => {
+                    return Box::pin(futures::stream::once(async move {
+                        Err(ChatCompletionError::from(error))
+                    }))
+                }
+            };
+            anthropic_request
+                .tools(tools)
+                .tool_choice(anthropic_tool_choice(request.tool_choice()));
+        }
+
+        let anthropic_request = match anthropic_request
+            .build()
+            .map_err(|e| ChatCompletionError::LLM(Box::new(e)))
+        {
+            Ok(request) => request,
+            Err(error) => return Box::pin(futures::stream::once(async move { Err(error) })),
+        };
+
+        let client = self.client.clone();
+
+        Box::pin(async_stream::stream! {
+            let mut events = match client.messages().create_stream(anthropic_request).await {
+                Ok(events) => events,
+                Err(error) => {
+                    yield Err(ChatCompletionError::LLM(Box::new(error)));
+                    return;
+                }
+            };
+
+            let mut tool_uses: HashMap<usize, PartialToolUse> = HashMap::new();
+
+            while let Some(event) = events.next().await {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(error) => {
+                        yield Err(ChatCompletionError::LLM(Box::new(error)));
+                        break;
+                    }
+                };
+
+                match event {
+                    MessageStreamEvent::ContentBlockStart { index, content_block } => {
+                        if let ContentBlock::ToolUse { id, name, .. } = content_block {
+                            tool_uses.insert(index, PartialToolUse { id, name, input_json: String::new() });
+                        }
+                    }
+                    MessageStreamEvent::ContentBlockDelta { index, delta } => match delta {
+                        ContentBlockDelta::TextDelta { text } => {
+                            // Yield only the new fragment: callers treat each item as a delta
+                            // (the OpenAI-compatible server forwards each one as its own chunk),
+                            // so yielding the cumulative text here would duplicate it on every
+                            // chunk.
+                            yield ChatCompletionResponse::builder()
+                                .maybe_message(Some(text))
+                                .maybe_tool_calls(None)
+                                .build()
+                                .map_err(ChatCompletionError::from);
+                        }
+                        ContentBlockDelta::InputJsonDelta { partial_json } => {
+                            if let Some(partial) = tool_uses.get_mut(&index) {
+                                partial.input_json.push_str(&partial_json);
+                            }
+                        }
+                    },
+                    MessageStreamEvent::ContentBlockStop { index } => {
+                        if let Some(partial) = tool_uses.remove(&index) {
+                            yield match partial.finalize() {
+                                Ok(tool_call) => ChatCompletionResponse::builder()
+                                    .maybe_message(None)
+                                    .maybe_tool_calls(Some(vec![tool_call]))
+                                    .build()
+                                    .map_err(ChatCompletionError::from),
+                                Err(error) => Err(error),
+                            };
+                        }
+                    }
+                    MessageStreamEvent::MessageStop => break,
+                    _ => {}
+                }
+            }
+        })
+    }
+}
+
+/// A tool call as it is incrementally assembled from `content_block_start` /
+/// `input_json_delta` / `content_block_stop` events. Anthropic streams the tool arguments as
+/// fragments of a JSON string, so we can only parse the accumulated buffer once the block
+/// closes.
+struct PartialToolUse {
+    id: String,
+    name: String,
+    input_json: String,
+}
+
+impl PartialToolUse {
+    fn finalize(self) -> Result<ToolCall, ChatCompletionError> {
+        // A tool called with no arguments never receives an `input_json_delta`, so
+        // `input_json` stays empty here. Treat that the same as `complete()`'s non-streaming
+        // path, where Anthropic always reports `{}` for a no-arg tool call, rather than
+        // storing an empty string that fails to parse as JSON later.
+        let args = if self.input_json.is_empty() {
+            "{}".to_string()
+        } else {
+            self.input_json
+        };
+
+        serde_json::from_str::<serde_json::Value>(&args).map_err(|error| {
+            ChatCompletionError::InvalidToolCall {
+                name: self.name.clone(),
+                reason: error.to_string(),
+            }
+        })?;
+
+        ToolCall::builder()
+            .id(self.id)
+            .name(self.name)
+            .args(args)
+            .build()
+            .map_err(ChatCompletionError::from)
+    }
+}
+
+/// Anthropic carries the system prompt as a dedicated top-level `system` field rather than a
+/// message in the `messages` array (mirroring Bedrock's `SystemContentBlock`, which is hoisted
+/// out of `messages` the same way). Concatenates every [`ChatMessage::System`] in order, since
+/// Anthropic only accepts a single system string.
+fn system_prompt(messages: &[ChatMessage]) -> Option<String> {
+    let system = messages
+        .iter()
+        .filter_map(|message| match message {
+            ChatMessage::System(msg) => Some(msg.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    if system.is_empty() {
+        None
+    } else {
+        Some(system)
+    }
 }
 
 #[allow(clippy::items_after_statements)]
@@ -109,7 +315,8 @@ fn message_to_antropic(message: &ChatMessage) -> Result<Message> {
                 .content(tool_output.content().unwrap_or("Success"))
                 .build()?,
         ),
-        Summary(msg) | System(msg) | User(msg) => builder.content(msg),
+        Summary(msg) | User(msg) => builder.content(msg),
+        System(_) => unreachable!("system messages are split out before this point"),
         Assistant(msg, tool_calls) => {
             builder.role(MessageRole::Assistant);
 
@@ -121,10 +328,14 @@ fn message_to_antropic(message: &ChatMessage) -> Result<Message> {
 
             if let Some(tool_calls) = tool_calls {
                 for tool_call in tool_calls {
+                    let args = tool_call
+                        .parsed_args()
+                        .context("Tool call arguments must be valid JSON")?;
+
                     let tool_call = ToolUseBuilder::default()
                         .id(tool_call.id())
                         .name(tool_call.name())
-                        .input(tool_call.args())
+                        .input(args)
                         .build()?;
 
                     content_list.push(tool_call.into());
@@ -140,34 +351,45 @@ fn message_to_antropic(message: &ChatMessage) -> Result<Message> {
     builder.build().context("Failed to build message")
 }
 
+/// Applies sampling/length options to an in-progress request, falling back to
+/// [`DEFAULT_MAX_TOKENS`] since Anthropic rejects requests without `max_tokens`.
+fn apply_options(
+    anthropic_request: &mut CreateMessagesRequestBuilder,
+    options: &ChatCompletionRequestOptions,
+) {
+    anthropic_request.max_tokens(options.max_tokens.unwrap_or(DEFAULT_MAX_TOKENS));
+
+    if let Some(temperature) = options.temperature {
+        anthropic_request.temperature(temperature);
+    }
+
+    if let Some(top_p) = options.top_p {
+        anthropic_request.top_p(top_p);
+    }
+
+    if let Some(stop_sequences) = options.stop_sequences.clone() {
+        anthropic_request.stop_sequences(stop_sequences);
+    }
+}
+
+/// Translates the backend-agnostic [`ToolChoice`] into Anthropic's `tool_choice`.
+/// `ToolChoice::None` is handled by the caller, which skips attaching tools entirely rather
+/// than calling this.
+fn anthropic_tool_choice(choice: &ToolChoice) -> AnthropicToolChoice {
+    match choice {
+        ToolChoice::Auto | ToolChoice::None => AnthropicToolChoice::Auto,
+        ToolChoice::Required => AnthropicToolChoice::Any,
+        ToolChoice::Specific(name) => AnthropicToolChoice::Tool { name: name.clone() },
+    }
+}
+
 fn tools_to_anthropic(
     spec: &ToolSpec,
 ) -> Result<serde_json::value::Map<String, serde_json::Value>> {
-    let properties = spec
-        .parameters
-        .iter()
-        .map(|param| {
-            let map = json!({
-                param.name: {
-                    "type": "string",
-                    "description": param.description,
-                }
-            })
-            .as_object()
-            .context("Failed to build tool")?
-            .to_owned();
-
-            Ok(map)
-        })
-        .collect::<Result<Vec<_>>>()?;
     let map = json!({
         "name": spec.name,
         "description": spec.description,
-        "input_schema": {
-            "type": "object",
-            "properties": properties,
-        },
-        "required": spec.parameters.iter().filter(|param| param.required).map(|param| param.name).collect::<Vec<_>>(),
+        "input_schema": spec.parameters_schema(),
     })
     .as_object_mut()
     .context("Failed to build tool")?
@@ -344,4 +566,398 @@ mod tests {
             )
         );
     }
+
+    #[test_log::test(tokio::test)]
+    async fn test_complete_stream_reassembles_text_and_split_tool_call() {
+        let mock_server = MockServer::start().await;
+
+        // A text delta split across two SSE events, plus a tool call whose `input_json`
+        // arrives as two separate `input_json_delta` fragments.
+        let body = [
+            r#"event: content_block_start
+data: {"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}
+
+"#,
+            r#"event: content_block_delta
+data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hel"}}
+
+"#,
+            r#"event: content_block_delta
+data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"lo"}}
+
+"#,
+            r#"event: content_block_stop
+data: {"type":"content_block_stop","index":0}
+
+"#,
+            r#"event: content_block_start
+data: {"type":"content_block_start","index":1,"content_block":{"type":"tool_use","id":"toolu_01","name":"get_weather","input":{}}}
+
+"#,
+            r#"event: content_block_delta
+data: {"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"{\"location\":"}}
+
+"#,
+            r#"event: content_block_delta
+data: {"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"\"SF\"}"}}
+
+"#,
+            r#"event: content_block_stop
+data: {"type":"content_block_stop","index":1}
+
+"#,
+            r#"event: message_stop
+data: {"type":"message_stop"}
+
+"#,
+        ]
+        .concat();
+
+        Mock::given(method("POST"))
+            .and(path("/v1/messages"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_raw(body, "text/event-stream"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = async_anthropic::Client::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let mut client_builder = Anthropic::builder();
+        client_builder.client(client);
+        let client = client_builder.build().unwrap();
+
+        let request = ChatCompletionRequest::builder()
+            .messages(vec![ChatMessage::User("hello".into())])
+            .tools_spec(HashSet::from([FakeTool().tool_spec()]))
+            .build()
+            .unwrap();
+
+        let mut stream = client.complete_stream(&request).await;
+
+        let mut message = String::new();
+        let mut tool_call = None;
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.unwrap();
+            if let Some(text) = chunk.message {
+                message.push_str(&text);
+            }
+            if let Some(mut tool_calls) = chunk.tool_calls {
+                tool_call = tool_calls.pop();
+            }
+        }
+
+        // Each delta must carry only its own fragment; concatenating them here is the
+        // caller's job, as `chat_completion_response_to_openai_chunk` forwards each fragment
+        // as its own chunk without joining them.
+        assert_eq!(message, "Hello");
+
+        let tool_call = tool_call.expect("tool call should have been yielded");
+        assert_eq!(tool_call.name(), "get_weather");
+        assert_eq!(
+            tool_call.args(),
+            Some(json!({"location": "SF"}).to_string().as_str())
+        );
+    }
+
+    async fn sent_request_body(mock_server: &MockServer) -> serde_json::Value {
+        let requests = mock_server
+            .received_requests()
+            .await
+            .expect("request recording must be enabled");
+        serde_json::from_slice(&requests[0].body).unwrap()
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn test_complete_routes_system_message_through_system_field() {
+        let mock_server = MockServer::start().await;
+
+        let mock_response = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "content": [{"type": "text", "text": "ok"}]
+        }));
+
+        Mock::given(method("POST"))
+            .and(path("/v1/messages"))
+            .respond_with(mock_response)
+            .mount(&mock_server)
+            .await;
+
+        let client = async_anthropic::Client::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let mut client_builder = Anthropic::builder();
+        client_builder.client(client);
+        let client = client_builder.build().unwrap();
+
+        let request = ChatCompletionRequest::builder()
+            .messages(vec![
+                ChatMessage::System("You are a helpful assistant.".into()),
+                ChatMessage::User("hello".into()),
+            ])
+            .build()
+            .unwrap();
+
+        client.complete(&request).await.unwrap();
+
+        let sent = sent_request_body(&mock_server).await;
+
+        assert_eq!(sent["system"], json!("You are a helpful assistant."));
+
+        // The system message must not also show up as a `user` turn, which would give
+        // Anthropic two consecutive `user`-role messages and a 400 on the real API.
+        let messages = sent["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0]["role"], "user");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn test_complete_stream_routes_system_message_through_system_field() {
+        let mock_server = MockServer::start().await;
+
+        let body = concat!(
+            "event: message_stop\n",
+            "data: {\"type\":\"message_stop\"}\n",
+            "\n",
+        );
+
+        Mock::given(method("POST"))
+            .and(path("/v1/messages"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_raw(body, "text/event-stream"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = async_anthropic::Client::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let mut client_builder = Anthropic::builder();
+        client_builder.client(client);
+        let client = client_builder.build().unwrap();
+
+        let request = ChatCompletionRequest::builder()
+            .messages(vec![
+                ChatMessage::System("You are a helpful assistant.".into()),
+                ChatMessage::User("hello".into()),
+            ])
+            .build()
+            .unwrap();
+
+        let mut stream = client.complete_stream(&request).await;
+        while stream.next().await.is_some() {}
+
+        let sent = sent_request_body(&mock_server).await;
+
+        assert_eq!(sent["system"], json!("You are a helpful assistant."));
+
+        let messages = sent["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0]["role"], "user");
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn test_complete_resends_assistant_tool_call_as_object_input() {
+        // Simulates the second round trip of a multi-step tool-calling conversation (as driven
+        // by `ToolCallLoop::run`), where a prior assistant turn's tool call is fed back in.
+        let mock_server = MockServer::start().await;
+
+        let mock_response = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "content": [{"type": "text", "text": "ok"}]
+        }));
+
+        Mock::given(method("POST"))
+            .and(path("/v1/messages"))
+            .respond_with(mock_response)
+            .mount(&mock_server)
+            .await;
+
+        let client = async_anthropic::Client::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let mut client_builder = Anthropic::builder();
+        client_builder.client(client);
+        let client = client_builder.build().unwrap();
+
+        let tool_call = ToolCall::builder()
+            .id("toolu_01")
+            .name("get_weather")
+            .args(json!({"location": "SF"}).to_string())
+            .build()
+            .unwrap();
+
+        let request = ChatCompletionRequest::builder()
+            .messages(vec![
+                ChatMessage::User("what's the weather in SF?".into()),
+                ChatMessage::Assistant(None, Some(vec![tool_call])),
+            ])
+            .build()
+            .unwrap();
+
+        client.complete(&request).await.unwrap();
+
+        let sent = sent_request_body(&mock_server).await;
+        let messages = sent["messages"].as_array().unwrap();
+        let tool_use = &messages[1]["content"][0];
+
+        // Must be a re-parsed JSON object, not the raw args string wrapped as `Value::String`.
+        assert_eq!(tool_use["input"], json!({"location": "SF"}));
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn test_complete_stream_yields_invalid_tool_call_for_malformed_json() {
+        let mock_server = MockServer::start().await;
+
+        // The tool's `input_json_delta` fragments reassemble into invalid JSON.
+        let body = concat!(
+            "event: content_block_start\n",
+            "data: {\"type\":\"content_block_start\",\"index\":0,\"content_block\":{\"type\":\"tool_use\",\"id\":\"toolu_01\",\"name\":\"get_weather\",\"input\":{}}}\n",
+            "\n",
+            "event: content_block_delta\n",
+            "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"{not valid json\"}}\n",
+            "\n",
+            "event: content_block_stop\n",
+            "data: {\"type\":\"content_block_stop\",\"index\":0}\n",
+            "\n",
+            "event: message_stop\n",
+            "data: {\"type\":\"message_stop\"}\n",
+            "\n",
+        );
+
+        Mock::given(method("POST"))
+            .and(path("/v1/messages"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_raw(body, "text/event-stream"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = async_anthropic::Client::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let mut client_builder = Anthropic::builder();
+        client_builder.client(client);
+        let client = client_builder.build().unwrap();
+
+        let request = ChatCompletionRequest::builder()
+            .messages(vec![ChatMessage::User("hello".into())])
+            .tools_spec(HashSet::from([FakeTool().tool_spec()]))
+            .build()
+            .unwrap();
+
+        let mut stream = client.complete_stream(&request).await;
+
+        let mut error = None;
+        while let Some(chunk) = stream.next().await {
+            if let Err(e) = chunk {
+                error = Some(e);
+                break;
+            }
+        }
+
+        match error.expect("stream should yield an error for malformed tool-call JSON") {
+            ChatCompletionError::InvalidToolCall { name, reason } => {
+                assert_eq!(name, "get_weather");
+                assert!(!reason.is_empty());
+            }
+            other => panic!("expected InvalidToolCall, got {other:?}"),
+        }
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn test_complete_defaults_max_tokens_when_unset() {
+        let mock_server = MockServer::start().await;
+
+        let mock_response = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "content": [{"type": "text", "text": "ok"}]
+        }));
+
+        Mock::given(method("POST"))
+            .and(path("/v1/messages"))
+            .respond_with(mock_response)
+            .mount(&mock_server)
+            .await;
+
+        let client = async_anthropic::Client::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let mut client_builder = Anthropic::builder();
+        client_builder.client(client);
+        let client = client_builder.build().unwrap();
+
+        let request = ChatCompletionRequest::builder()
+            .messages(vec![ChatMessage::User("hello".into())])
+            .build()
+            .unwrap();
+
+        client.complete(&request).await.unwrap();
+
+        let sent = sent_request_body(&mock_server).await;
+        assert_eq!(sent["max_tokens"], json!(DEFAULT_MAX_TOKENS));
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn test_complete_applies_request_options() {
+        let mock_server = MockServer::start().await;
+
+        let mock_response = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "content": [{"type": "text", "text": "ok"}]
+        }));
+
+        Mock::given(method("POST"))
+            .and(path("/v1/messages"))
+            .respond_with(mock_response)
+            .mount(&mock_server)
+            .await;
+
+        let client = async_anthropic::Client::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let mut client_builder = Anthropic::builder();
+        client_builder.client(client);
+        let client = client_builder.build().unwrap();
+
+        let options = ChatCompletionRequestOptions::builder()
+            .max_tokens(256u32)
+            .temperature(0.5f32)
+            .top_p(0.9f32)
+            .stop_sequences(vec!["STOP".to_string()])
+            .build()
+            .unwrap();
+
+        let request = ChatCompletionRequest::builder()
+            .messages(vec![ChatMessage::User("hello".into())])
+            .options(options)
+            .build()
+            .unwrap();
+
+        client.complete(&request).await.unwrap();
+
+        let sent = sent_request_body(&mock_server).await;
+        assert_eq!(sent["max_tokens"], json!(256));
+        assert_eq!(sent["temperature"], json!(0.5));
+        assert_eq!(sent["top_p"], json!(0.9));
+        assert_eq!(sent["stop_sequences"], json!(["STOP"]));
+    }
 }
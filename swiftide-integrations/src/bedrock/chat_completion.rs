@@ -0,0 +1,296 @@
+use anyhow::{Context as _, Result};
+use async_trait::async_trait;
+use aws_sdk_bedrockruntime::types::{
+    ContentBlock, ConversationRole, InferenceConfiguration, Message, SystemContentBlock,
+    Tool as BedrockTool, ToolConfiguration, ToolInputSchema, ToolResultBlock,
+    ToolResultContentBlock, ToolSpecification, ToolUseBlock,
+};
+use aws_smithy_types::Document;
+use swiftide_core::{
+    chat_completion::{
+        errors::ChatCompletionError, ChatCompletionRequest, ChatCompletionRequestOptions,
+        ChatCompletionResponse, ChatMessage, ToolCall, ToolChoice, ToolSpec,
+    },
+    ChatCompletion,
+};
+
+use super::Bedrock;
+
+/// Some Bedrock-hosted models (e.g. Claude) reject requests without a `maxTokens`; fall back
+/// to this when the caller didn't set one via `ChatCompletionRequestOptions`.
+const DEFAULT_MAX_TOKENS: i32 = 4096;
+
+#[async_trait]
+impl ChatCompletion for Bedrock {
+    #[tracing::instrument(skip_all, err)]
+    async fn complete(
+        &self,
+        request: &ChatCompletionRequest,
+    ) -> Result<ChatCompletionResponse, ChatCompletionError> {
+        let model = &self.default_options.prompt_model;
+
+        let system = request
+            .messages()
+            .iter()
+            .filter_map(|message| match message {
+                ChatMessage::System(msg) => Some(SystemContentBlock::Text(msg.clone())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let messages = request
+            .messages()
+            .iter()
+            .filter(|message| !matches!(message, ChatMessage::System(_)))
+            .map(message_to_bedrock)
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut converse = self
+            .client
+            .converse()
+            .model_id(model)
+            .set_system(Some(system))
+            .set_messages(Some(messages))
+            .inference_config(inference_config(request.options())?);
+
+        // `ToolChoice::None` forbids tool use; the only way to express that on Bedrock's
+        // Converse API is to not send a `toolConfig` at all. Forcing a specific/any tool is
+        // left to Bedrock's own default (`auto`) until this backend threads through the rest
+        // of `ToolChoice`.
+        if !request.tools_spec.is_empty() && *request.tool_choice() != ToolChoice::None {
+            let tools = request
+                .tools_spec()
+                .iter()
+                .map(tools_to_bedrock)
+                .collect::<Result<Vec<_>>>()?;
+
+            converse = converse.tool_config(
+                ToolConfiguration::builder()
+                    .set_tools(Some(tools))
+                    .build()
+                    .map_err(|e| ChatCompletionError::LLM(Box::new(e)))?,
+            );
+        }
+
+        let response = converse
+            .send()
+            .await
+            .map_err(|e| ChatCompletionError::LLM(Box::new(e)))?;
+
+        let output_message = response
+            .output()
+            .and_then(|output| output.as_message().ok())
+            .cloned();
+
+        let content = output_message
+            .as_ref()
+            .map(Message::content)
+            .unwrap_or_default();
+
+        let message = content.iter().find_map(|block| match block {
+            ContentBlock::Text(text) => Some(text.clone()),
+            _ => None,
+        });
+
+        let maybe_tool_calls = content
+            .iter()
+            .filter_map(|block| match block {
+                ContentBlock::ToolUse(tool_use) => Some(tool_use),
+                _ => None,
+            })
+            .map(|tool_use| {
+                let tool_call = ToolCall::builder()
+                    .id(tool_use.tool_use_id())
+                    .name(tool_use.name())
+                    .args(document_to_json(tool_use.input()).to_string())
+                    .build()
+                    .expect("infallible");
+
+                tool_call
+                    .parsed_args()
+                    .map_err(|error| ChatCompletionError::InvalidToolCall {
+                        name: tool_call.name().to_string(),
+                        reason: format!("arguments must be valid JSON: {error}"),
+                    })?;
+
+                Ok(tool_call)
+            })
+            .collect::<Result<Vec<_>, ChatCompletionError>>()?;
+        let maybe_tool_calls = if maybe_tool_calls.is_empty() {
+            None
+        } else {
+            Some(maybe_tool_calls)
+        };
+
+        ChatCompletionResponse::builder()
+            .maybe_message(message)
+            .maybe_tool_calls(maybe_tool_calls)
+            .build()
+            .map_err(ChatCompletionError::from)
+    }
+}
+
+fn message_to_bedrock(message: &ChatMessage) -> Result<Message> {
+    let mut builder = Message::builder().role(ConversationRole::User);
+
+    match message {
+        ChatMessage::ToolOutput(tool_call, tool_output) => {
+            builder = builder.content(ContentBlock::ToolResult(
+                ToolResultBlock::builder()
+                    .tool_use_id(tool_call.id())
+                    .content(ToolResultContentBlock::Text(
+                        tool_output.content().unwrap_or("Success").to_string(),
+                    ))
+                    .build()
+                    .context("Failed to build tool result")?,
+            ));
+        }
+        ChatMessage::Summary(msg) | ChatMessage::User(msg) => {
+            builder = builder.content(ContentBlock::Text(msg.clone()));
+        }
+        ChatMessage::System(_) => unreachable!("system messages are split out before this point"),
+        ChatMessage::Assistant(msg, tool_calls) => {
+            builder = builder.role(ConversationRole::Assistant);
+
+            if let Some(msg) = msg {
+                builder = builder.content(ContentBlock::Text(msg.clone()));
+            }
+
+            for tool_call in tool_calls.iter().flatten() {
+                let args = tool_call
+                    .parsed_args()
+                    .context("Tool call arguments must be valid JSON")?;
+
+                builder = builder.content(ContentBlock::ToolUse(
+                    ToolUseBlock::builder()
+                        .tool_use_id(tool_call.id())
+                        .name(tool_call.name())
+                        .input(json_to_document(&args))
+                        .build()
+                        .context("Failed to build tool use")?,
+                ));
+            }
+        }
+    }
+
+    builder.build().context("Failed to build message")
+}
+
+fn tools_to_bedrock(spec: &ToolSpec) -> Result<BedrockTool> {
+    let input_schema = json_to_document(&spec.parameters_schema());
+
+    let tool_spec = ToolSpecification::builder()
+        .name(&spec.name)
+        .description(&spec.description)
+        .input_schema(ToolInputSchema::Json(input_schema))
+        .build()
+        .context("Failed to build tool spec")?;
+
+    Ok(BedrockTool::ToolSpec(tool_spec))
+}
+
+fn inference_config(options: &ChatCompletionRequestOptions) -> Result<InferenceConfiguration> {
+    InferenceConfiguration::builder()
+        .max_tokens(options.max_tokens.map_or(DEFAULT_MAX_TOKENS, |v| v as i32))
+        .set_temperature(options.temperature)
+        .set_top_p(options.top_p)
+        .set_stop_sequences(options.stop_sequences.clone())
+        .build()
+        .context("Failed to build inference config")
+}
+
+fn json_to_document(value: &serde_json::Value) -> Document {
+    serde_json::from_value(value.clone()).expect("serde_json::Value always converts to Document")
+}
+
+fn document_to_json(document: &Document) -> serde_json::Value {
+    serde_json::to_value(document).expect("Document always converts to serde_json::Value")
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_sdk_bedrockruntime::config::{Credentials, Region};
+    use swiftide_core::chat_completion::ChatCompletionRequest;
+    use wiremock::{
+        matchers::{method, path_regex},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+
+    async fn bedrock_against(mock_server: &MockServer) -> Bedrock {
+        let config = aws_sdk_bedrockruntime::Config::builder()
+            .behavior_version_latest()
+            .region(Region::new("us-east-1"))
+            .endpoint_url(mock_server.uri())
+            .credentials_provider(Credentials::for_tests())
+            .build();
+
+        Bedrock::builder()
+            .client(aws_sdk_bedrockruntime::Client::from_conf(config))
+            .default_options(super::super::Options {
+                prompt_model: "anthropic.claude-3-5-sonnet-20241022-v2:0".into(),
+            })
+            .build()
+            .unwrap()
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn test_complete_with_tool_use() {
+        let mock_server = MockServer::start().await;
+
+        let mock_response = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "output": {
+                "message": {
+                    "role": "assistant",
+                    "content": [
+                        {"text": "Checking the weather in San Francisco, CA."},
+                        {
+                            "toolUse": {
+                                "toolUseId": "tooluse_01",
+                                "name": "get_weather",
+                                "input": {"location": "San Francisco, CA"}
+                            }
+                        }
+                    ]
+                }
+            },
+            "stopReason": "tool_use",
+            "usage": {"inputTokens": 10, "outputTokens": 20, "totalTokens": 30}
+        }));
+
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/model/.*/converse$"))
+            .respond_with(mock_response)
+            .mount(&mock_server)
+            .await;
+
+        let client = bedrock_against(&mock_server).await;
+
+        let request = ChatCompletionRequest::builder()
+            .messages(vec![ChatMessage::User("hello".into())])
+            .build()
+            .unwrap();
+
+        let result = client.complete(&request).await.unwrap();
+
+        assert_eq!(
+            result.message,
+            Some("Checking the weather in San Francisco, CA.".into())
+        );
+
+        let tool_call = result
+            .tool_calls
+            .and_then(|calls| calls.into_iter().next())
+            .expect("tool call should have been returned");
+        assert_eq!(tool_call.name(), "get_weather");
+        assert_eq!(
+            tool_call.args(),
+            Some(
+                serde_json::json!({"location": "San Francisco, CA"})
+                    .to_string()
+                    .as_str()
+            )
+        );
+    }
+}
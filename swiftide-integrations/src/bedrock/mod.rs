@@ -0,0 +1,43 @@
+mod chat_completion;
+
+use aws_sdk_bedrockruntime::Client;
+use derive_builder::Builder;
+
+/// A client for AWS Bedrock's Converse API, giving access to Claude, Llama, Mistral and other
+/// Bedrock-hosted models through a single AWS-authenticated client.
+///
+/// Implements [`swiftide_core::ChatCompletion`], sibling to [`crate::anthropic::Anthropic`].
+#[derive(Clone, Builder)]
+#[builder(setter(into))]
+pub struct Bedrock {
+    client: Client,
+
+    #[builder(default)]
+    default_options: Options,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub prompt_model: String,
+}
+
+impl Bedrock {
+    pub fn builder() -> BedrockBuilder {
+        BedrockBuilder::default()
+    }
+
+    /// Builds a `Bedrock` client from the ambient AWS configuration (environment, profile,
+    /// IMDS, etc), using `model_id` (e.g. `"anthropic.claude-3-5-sonnet-20241022-v2:0"`) as the
+    /// default model for `complete`.
+    pub async fn build(model_id: impl Into<String>) -> Self {
+        let config = aws_config::load_from_env().await;
+
+        Self::builder()
+            .client(Client::new(&config))
+            .default_options(Options {
+                prompt_model: model_id.into(),
+            })
+            .build()
+            .expect("infallible")
+    }
+}
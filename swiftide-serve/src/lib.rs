@@ -0,0 +1,8 @@
+//! Exposes any [`swiftide_core::ChatCompletion`] implementor behind an HTTP server that speaks
+//! the OpenAI `/v1/chat/completions` contract, so existing OpenAI-client tooling can talk to
+//! any swiftide-backed model (Anthropic, Bedrock, ...) without change.
+
+mod openai;
+mod server;
+
+pub use server::Serve;
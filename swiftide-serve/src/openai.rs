@@ -0,0 +1,454 @@
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use swiftide_core::chat_completion::{
+    ChatCompletionRequest, ChatCompletionResponse, ChatMessage, ParamSpec, ParamType, ToolCall,
+    ToolChoice, ToolSpec,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct OpenAiChatCompletionRequest {
+    pub model: String,
+    pub messages: Vec<OpenAiMessage>,
+    #[serde(default)]
+    pub tools: Vec<OpenAiTool>,
+    #[serde(default)]
+    pub tool_choice: Option<serde_json::Value>,
+    #[serde(default)]
+    pub stream: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenAiMessage {
+    pub role: String,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub tool_calls: Option<Vec<OpenAiToolCall>>,
+    #[serde(default)]
+    pub tool_call_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenAiTool {
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub function: OpenAiFunction,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenAiFunction {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub parameters: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpenAiToolCall {
+    pub id: String,
+    #[serde(rename = "type", default = "default_function_type")]
+    pub ty: String,
+    pub function: OpenAiFunctionCall,
+}
+
+fn default_function_type() -> String {
+    "function".into()
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpenAiFunctionCall {
+    pub name: String,
+    pub arguments: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OpenAiChatCompletionResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub model: String,
+    pub choices: Vec<OpenAiChoice>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OpenAiChoice {
+    pub index: u32,
+    pub message: OpenAiResponseMessage,
+    pub finish_reason: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OpenAiResponseMessage {
+    pub role: &'static str,
+    pub content: Option<String>,
+    pub tool_calls: Option<Vec<OpenAiToolCall>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OpenAiChatCompletionChunk {
+    pub id: String,
+    pub object: &'static str,
+    pub model: String,
+    pub choices: Vec<OpenAiChunkChoice>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OpenAiChunkChoice {
+    pub index: u32,
+    pub delta: OpenAiDelta,
+    pub finish_reason: Option<&'static str>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct OpenAiDelta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<OpenAiToolCall>>,
+}
+
+pub fn request_to_chat_completion_request(
+    request: &OpenAiChatCompletionRequest,
+) -> Result<ChatCompletionRequest> {
+    let messages = request
+        .messages
+        .iter()
+        .map(openai_message_to_chat_message)
+        .collect::<Result<Vec<_>>>()?;
+
+    let tools_spec = request
+        .tools
+        .iter()
+        .map(openai_tool_to_tool_spec)
+        .collect::<Result<std::collections::HashSet<_>>>()?;
+
+    let tool_choice = request
+        .tool_choice
+        .as_ref()
+        .map(openai_tool_choice_to_tool_choice)
+        .unwrap_or_default();
+
+    ChatCompletionRequest::builder()
+        .messages(messages)
+        .tools_spec(tools_spec)
+        .tool_choice(tool_choice)
+        .build()
+        .context("Failed to build chat completion request")
+}
+
+/// Translates OpenAI's `tool_choice` (`"auto"` / `"none"` / `"required"`, or
+/// `{"type": "function", "function": {"name": ...}}` to force a specific tool) into the
+/// backend-agnostic [`ToolChoice`]. Defaults to `Auto` for anything unrecognized.
+fn openai_tool_choice_to_tool_choice(value: &serde_json::Value) -> ToolChoice {
+    match value {
+        serde_json::Value::String(choice) => match choice.as_str() {
+            "none" => ToolChoice::None,
+            "required" => ToolChoice::Required,
+            _ => ToolChoice::Auto,
+        },
+        serde_json::Value::Object(_) => value
+            .get("function")
+            .and_then(|function| function.get("name"))
+            .and_then(serde_json::Value::as_str)
+            .map(|name| ToolChoice::Specific(name.to_string()))
+            .unwrap_or(ToolChoice::Auto),
+        _ => ToolChoice::Auto,
+    }
+}
+
+fn openai_message_to_chat_message(message: &OpenAiMessage) -> Result<ChatMessage> {
+    match message.role.as_str() {
+        "system" => Ok(ChatMessage::System(message.content.clone().unwrap_or_default())),
+        "user" => Ok(ChatMessage::User(message.content.clone().unwrap_or_default())),
+        "assistant" => {
+            let tool_calls = message.tool_calls.as_ref().map(|tool_calls| {
+                tool_calls
+                    .iter()
+                    .map(|tool_call| {
+                        ToolCall::builder()
+                            .id(tool_call.id.clone())
+                            .name(tool_call.function.name.clone())
+                            .args(tool_call.function.arguments.clone())
+                            .build()
+                            .expect("infallible")
+                    })
+                    .collect()
+            });
+
+            Ok(ChatMessage::Assistant(message.content.clone(), tool_calls))
+        }
+        "tool" => {
+            let tool_call_id = message
+                .tool_call_id
+                .clone()
+                .context("Tool message is missing tool_call_id")?;
+            let tool_call = ToolCall::builder()
+                .id(tool_call_id)
+                .name("")
+                .build()
+                .expect("infallible");
+
+            Ok(ChatMessage::ToolOutput(
+                tool_call,
+                swiftide_core::chat_completion::ToolOutput::new(
+                    message.content.clone().unwrap_or_default(),
+                ),
+            ))
+        }
+        other => anyhow::bail!("Unsupported OpenAI message role: {other}"),
+    }
+}
+
+fn openai_tool_to_tool_spec(tool: &OpenAiTool) -> Result<ToolSpec> {
+    let parameters = tool
+        .function
+        .parameters
+        .get("properties")
+        .and_then(serde_json::Value::as_object)
+        .map(|properties| {
+            json_schema_properties_to_params(properties, tool.function.parameters.get("required"))
+        })
+        .unwrap_or_default();
+
+    ToolSpec::builder()
+        .name(tool.function.name.clone())
+        .description(tool.function.description.clone())
+        .parameters(parameters)
+        .build()
+        .context("Failed to build tool spec")
+}
+
+/// Translates a JSON-schema `properties` map (plus its sibling `required` array) into
+/// `ParamSpec`s, recursing into array items and nested objects via [`json_schema_to_param_type`]
+/// so client-declared types survive instead of being downgraded to `ParamType::String`.
+fn json_schema_properties_to_params(
+    properties: &serde_json::Map<String, serde_json::Value>,
+    required: Option<&serde_json::Value>,
+) -> Vec<ParamSpec> {
+    let required = required
+        .and_then(serde_json::Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    properties
+        .iter()
+        .map(|(name, schema)| {
+            let mut builder = ParamSpec::builder();
+            builder
+                .name(name.clone())
+                .description(
+                    schema
+                        .get("description")
+                        .and_then(serde_json::Value::as_str)
+                        .unwrap_or_default(),
+                )
+                .required(required.contains(&name.as_str()))
+                .ty(json_schema_to_param_type(schema));
+
+            if let Some(enum_values) = schema.get("enum").and_then(serde_json::Value::as_array) {
+                builder.enum_values(
+                    enum_values
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(str::to_string)
+                        .collect::<Vec<_>>(),
+                );
+            }
+
+            builder.build().expect("infallible")
+        })
+        .collect()
+}
+
+fn json_schema_to_param_type(schema: &serde_json::Value) -> ParamType {
+    match schema.get("type").and_then(serde_json::Value::as_str) {
+        Some("number") => ParamType::Number,
+        Some("integer") => ParamType::Integer,
+        Some("boolean") => ParamType::Boolean,
+        Some("array") => {
+            let items = schema
+                .get("items")
+                .map(json_schema_to_param_type)
+                .unwrap_or(ParamType::String);
+
+            ParamType::Array {
+                items: Box::new(items),
+            }
+        }
+        Some("object") => {
+            let properties = schema
+                .get("properties")
+                .and_then(serde_json::Value::as_object)
+                .map(|properties| {
+                    json_schema_properties_to_params(properties, schema.get("required"))
+                })
+                .unwrap_or_default();
+
+            ParamType::Object { properties }
+        }
+        _ => ParamType::String,
+    }
+}
+
+pub fn chat_completion_response_to_openai(
+    id: String,
+    model: String,
+    response: ChatCompletionResponse,
+) -> OpenAiChatCompletionResponse {
+    let finish_reason = if response.tool_calls.is_some() {
+        "tool_calls"
+    } else {
+        "stop"
+    };
+
+    OpenAiChatCompletionResponse {
+        id,
+        object: "chat.completion",
+        model,
+        choices: vec![OpenAiChoice {
+            index: 0,
+            message: OpenAiResponseMessage {
+                role: "assistant",
+                content: response.message,
+                tool_calls: response.tool_calls.map(tool_calls_to_openai),
+            },
+            finish_reason,
+        }],
+    }
+}
+
+pub fn chat_completion_response_to_openai_chunk(
+    id: String,
+    model: String,
+    response: ChatCompletionResponse,
+) -> OpenAiChatCompletionChunk {
+    OpenAiChatCompletionChunk {
+        id,
+        object: "chat.completion.chunk",
+        model,
+        choices: vec![OpenAiChunkChoice {
+            index: 0,
+            delta: OpenAiDelta {
+                content: response.message,
+                tool_calls: response.tool_calls.map(tool_calls_to_openai),
+            },
+            finish_reason: None,
+        }],
+    }
+}
+
+fn tool_calls_to_openai(tool_calls: Vec<ToolCall>) -> Vec<OpenAiToolCall> {
+    tool_calls
+        .into_iter()
+        .map(|tool_call| OpenAiToolCall {
+            id: tool_call.id().to_string(),
+            ty: "function".into(),
+            function: OpenAiFunctionCall {
+                name: tool_call.name().to_string(),
+                arguments: tool_call.args().unwrap_or_default().to_string(),
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use swiftide_core::chat_completion::ChatCompletionResponse;
+
+    use super::*;
+
+    #[test]
+    fn test_request_to_chat_completion_request_round_trip() {
+        let request: OpenAiChatCompletionRequest = serde_json::from_value(serde_json::json!({
+            "model": "gpt-4o",
+            "messages": [{"role": "user", "content": "what's the weather in SF?"}],
+            "tools": [{
+                "type": "function",
+                "function": {
+                    "name": "get_weather",
+                    "description": "Gets the weather",
+                    "parameters": {
+                        "type": "object",
+                        "properties": {
+                            "location": {"type": "string", "description": "City name"},
+                            "unit": {
+                                "type": "string",
+                                "description": "Temperature unit",
+                                "enum": ["celsius", "fahrenheit"]
+                            },
+                            "days": {"type": "integer", "description": "Forecast length"}
+                        },
+                        "required": ["location"]
+                    }
+                }
+            }],
+            "tool_choice": {"type": "function", "function": {"name": "get_weather"}}
+        }))
+        .unwrap();
+
+        let chat_request = request_to_chat_completion_request(&request).unwrap();
+
+        assert_eq!(chat_request.messages().len(), 1);
+        assert_eq!(
+            *chat_request.tool_choice(),
+            ToolChoice::Specific("get_weather".into())
+        );
+
+        let tool_spec = chat_request
+            .tools_spec()
+            .iter()
+            .find(|spec| spec.name == "get_weather")
+            .expect("get_weather tool spec should be present");
+
+        let location = tool_spec
+            .parameters
+            .iter()
+            .find(|param| param.name == "location")
+            .unwrap();
+        assert_eq!(location.ty, ParamType::String);
+        assert!(location.required);
+
+        let unit = tool_spec
+            .parameters
+            .iter()
+            .find(|param| param.name == "unit")
+            .unwrap();
+        assert_eq!(unit.ty, ParamType::String);
+        assert_eq!(
+            unit.enum_values,
+            Some(vec!["celsius".to_string(), "fahrenheit".to_string()])
+        );
+
+        let days = tool_spec
+            .parameters
+            .iter()
+            .find(|param| param.name == "days")
+            .unwrap();
+        assert_eq!(days.ty, ParamType::Integer);
+        assert!(!days.required);
+    }
+
+    #[test]
+    fn test_chat_completion_response_to_openai_round_trip() {
+        let response = ChatCompletionResponse::builder()
+            .maybe_message(Some("mocked response".into()))
+            .maybe_tool_calls(None)
+            .build()
+            .unwrap();
+
+        let openai_response =
+            chat_completion_response_to_openai("chatcmpl-1".into(), "gpt-4o".into(), response);
+
+        assert_eq!(openai_response.choices.len(), 1);
+        assert_eq!(
+            openai_response.choices[0].message.content,
+            Some("mocked response".into())
+        );
+        assert_eq!(openai_response.choices[0].finish_reason, "stop");
+    }
+}
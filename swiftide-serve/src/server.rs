@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    response::{
+        sse::{Event, Sse},
+        IntoResponse, Response,
+    },
+    routing::post,
+    Json, Router,
+};
+use futures::StreamExt;
+use swiftide_core::ChatCompletion;
+
+use crate::openai::{
+    chat_completion_response_to_openai, chat_completion_response_to_openai_chunk,
+    request_to_chat_completion_request, OpenAiChatCompletionRequest,
+};
+
+/// Wraps a [`ChatCompletion`] implementor behind an HTTP server implementing the OpenAI
+/// `/v1/chat/completions` contract (streaming and non-streaming).
+pub struct Serve {
+    model: Arc<dyn ChatCompletion>,
+}
+
+impl Serve {
+    pub fn new(model: impl ChatCompletion + 'static) -> Self {
+        Self {
+            model: Arc::new(model),
+        }
+    }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/v1/chat/completions", post(chat_completions))
+            .with_state(self.model)
+    }
+
+    pub async fn bind(self, addr: impl Into<std::net::SocketAddr>) -> anyhow::Result<()> {
+        let addr = addr.into();
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+
+        tracing::info!(%addr, "[Serve] Listening for OpenAI-compatible requests");
+
+        axum::serve(listener, self.router()).await?;
+
+        Ok(())
+    }
+}
+
+async fn chat_completions(
+    State(model): State<Arc<dyn ChatCompletion>>,
+    Json(request): Json<OpenAiChatCompletionRequest>,
+) -> Response {
+    let model_name = request.model.clone();
+    let stream = request.stream;
+
+    let chat_request = match request_to_chat_completion_request(&request) {
+        Ok(chat_request) => chat_request,
+        Err(error) => return (axum::http::StatusCode::BAD_REQUEST, error.to_string()).into_response(),
+    };
+
+    let id = format!("chatcmpl-{}", uuid::Uuid::new_v4());
+
+    if stream {
+        let mut deltas = model.complete_stream(&chat_request).await;
+
+        let sse_stream = async_stream::stream! {
+            while let Some(delta) = deltas.next().await {
+                match delta {
+                    Ok(delta) => {
+                        let chunk = chat_completion_response_to_openai_chunk(
+                            id.clone(),
+                            model_name.clone(),
+                            delta,
+                        );
+                        yield Ok::<_, std::convert::Infallible>(
+                            Event::default().json_data(chunk).expect("infallible"),
+                        );
+                    }
+                    Err(error) => {
+                        tracing::error!(%error, "[Serve] Streaming chat completion failed");
+                        break;
+                    }
+                }
+            }
+
+            yield Ok(Event::default().data("[DONE]"));
+        };
+
+        Sse::new(sse_stream).into_response()
+    } else {
+        match model.complete(&chat_request).await {
+            Ok(response) => {
+                Json(chat_completion_response_to_openai(id, model_name, response)).into_response()
+            }
+            Err(error) => {
+                (axum::http::StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response()
+            }
+        }
+    }
+}